@@ -4,12 +4,13 @@
 //!
 //! ```rust
 //! use axum::{routing::get, Router};
-//! use axum_signed_urls::{SignedUrl, build};
+//! use axum_signed_urls::{build, SignedUrl, SignedUrlConfig};
 //!
 //! // This route will only be accessible if the URL is signed
 //! async fn handler(_: SignedUrl) -> String {
 //!     // This is how you create a signed URL
-//!     build("/path", vec![("foo", "bar")].into_iter().collect()).unwrap()
+//!     let config = SignedUrlConfig::new("super-secret-key");
+//!     build(&config, "/path", vec![("foo", "bar")].into_iter().collect()).unwrap()
 //! }
 //! ```
 //!
@@ -26,27 +27,117 @@
 #![warn(clippy::all, missing_docs, nonstandard_style, future_incompatible)]
 
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use axum::{
     async_trait,
-    extract::FromRequestParts,
-    http::{request::Parts, StatusCode},
+    extract::{FromRef, FromRequestParts},
+    http::{request::Parts, Method, StatusCode},
 };
 use hmac::{Hmac, Mac};
 use qstring::QString;
 use sha2::Sha256;
 use std::env;
+use std::fmt;
+
+/// Signing configuration for [`SignedUrl`], sourced from Axum application state.
+///
+/// Holds the key currently used to sign new URLs, plus any previously-used keys that should
+/// still be accepted when verifying a signature. This allows rotating `AXUM_SECRET` without
+/// instantly invalidating every outstanding link: retire the old key by moving it into
+/// [`with_previous_key`][Self::with_previous_key] instead of dropping it.
+#[derive(Clone)]
+pub struct SignedUrlConfig {
+    current_key: String,
+    previous_keys: Vec<String>,
+    require_method: bool,
+}
+
+/// Redacts `current_key`/`previous_keys` so signing secrets never end up in logs or panic
+/// messages via a `{:?}` on application state that embeds this config.
+impl fmt::Debug for SignedUrlConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignedUrlConfig")
+            .field("current_key", &"[redacted]")
+            .field(
+                "previous_keys",
+                &vec!["[redacted]"; self.previous_keys.len()],
+            )
+            .field("require_method", &self.require_method)
+            .finish()
+    }
+}
+
+impl SignedUrlConfig {
+    /// Creates a config that signs and verifies with a single, current key.
+    pub fn new(current_key: impl Into<String>) -> Self {
+        Self {
+            current_key: current_key.into(),
+            previous_keys: Vec::new(),
+            require_method: false,
+        }
+    }
+
+    /// Registers a previously-used key that should still be accepted when verifying signatures.
+    #[must_use]
+    pub fn with_previous_key(mut self, key: impl Into<String>) -> Self {
+        self.previous_keys.push(key.into());
+        self
+    }
+
+    /// Rejects method-agnostic signatures, requiring every signature to be bound to the
+    /// request's HTTP method via [`build_for_method`], [`build_for_method_with_headers`], or
+    /// [`build_for_method_with_expiry`].
+    ///
+    /// Leave this off while migrating from [`build`]/[`build_with_expiry`] to
+    /// [`build_for_method`], so outstanding method-agnostic links keep validating.
+    #[must_use]
+    pub fn require_method(mut self) -> Self {
+        self.require_method = true;
+        self
+    }
+
+    /// Builds a config from the `AXUM_SECRET` environment variable, with no previously-accepted
+    /// keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `AXUM_SECRET` is not set.
+    pub fn from_env() -> Result<Self> {
+        Ok(Self::new(
+            env::var("AXUM_SECRET").context("AXUM_SECRET not found")?,
+        ))
+    }
+
+    fn keys(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.current_key.as_str()).chain(self.previous_keys.iter().map(String::as_str))
+    }
+}
+
+/// Falls back to an env-backed [`SignedUrlConfig`] for handlers that don't carry their own state,
+/// so existing `()`-state apps keep working unchanged.
+impl FromRef<()> for SignedUrlConfig {
+    fn from_ref(_state: &()) -> Self {
+        Self::from_env().expect("AXUM_SECRET not found")
+    }
+}
 
 /// Extractor for signed URLs, acts as a middleware.
 #[derive(Debug)]
 pub struct SignedUrl;
 
 #[async_trait]
-impl<S> FromRequestParts<S> for SignedUrl {
+impl<S> FromRequestParts<S> for SignedUrl
+where
+    S: Sync,
+    SignedUrlConfig: FromRef<S>,
+{
     type Rejection = (StatusCode, &'static str);
 
-    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let config = SignedUrlConfig::from_ref(state);
+
         let url = parts.uri.path_and_query().unwrap();
 
         let (signature_parts, other_parts): (Vec<_>, Vec<_>) = QString::from(url.query().unwrap())
@@ -61,11 +152,71 @@ impl<S> FromRequestParts<S> for SignedUrl {
 
         let query = QString::new(other_parts);
         let unsigned_url = format!("{}{}", url.path(), stringify_query(&query));
-
-        if signature != hmac_sha256(&unsigned_url).unwrap() {
+        let method_bound_url = format!("{}{unsigned_url}", parts.method.as_str());
+
+        let signed_headers = query
+            .get("signed-headers")
+            .map(|names| {
+                names
+                    .split(',')
+                    .filter(|name| !name.is_empty())
+                    .map(|name| {
+                        let value = parts
+                            .headers
+                            .get(name)
+                            .ok_or((StatusCode::UNAUTHORIZED, "Invalid signature"))?
+                            .to_str()
+                            .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid signature"))?;
+
+                        Ok((name.to_string(), value.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, Self::Rejection>>()
+            })
+            .transpose()?;
+
+        let header_block = signed_headers.as_ref().map_or_else(String::new, |headers| {
+            canonical_headers(
+                &headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str()))
+                    .collect::<Vec<_>>(),
+            )
+        });
+
+        let candidates: Vec<String> = if config.require_method {
+            vec![format!("{method_bound_url}{header_block}")]
+        } else {
+            vec![
+                format!("{method_bound_url}{header_block}"),
+                format!("{unsigned_url}{header_block}"),
+            ]
+        };
+
+        let is_valid = config.keys().any(|key| {
+            candidates
+                .iter()
+                .any(|data| verify_signature(key, data, &signature).is_ok())
+        });
+
+        if !is_valid {
             return Err((StatusCode::UNAUTHORIZED, "Invalid signature"));
         }
 
+        if let Some(expires_at) = query.get("expires") {
+            let expires_at: u64 = expires_at
+                .parse()
+                .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid signature"))?;
+
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+
+            if now > expires_at {
+                return Err((StatusCode::UNAUTHORIZED, "URL expired"));
+            }
+        }
+
         Ok(SignedUrl)
     }
 }
@@ -75,35 +226,265 @@ impl<S> FromRequestParts<S> for SignedUrl {
 /// # Example
 ///
 /// ```rust
-/// use axum_signed_urls::build;
+/// use axum_signed_urls::{build, SignedUrlConfig};
 /// use std::collections::HashMap;
 ///
-/// // Make sure to set AXUM_SECRET to a secret value, e.g. in your .env file
-/// # std::env::set_var("AXUM_SECRET", "hunter2");
+/// let config = SignedUrlConfig::new("hunter2");
 ///
 /// let mut query = HashMap::new();
 /// query.insert("foo", "bar");
 /// query.insert("baz", "qux");
 ///
-/// let url = build("/path", query).unwrap();
+/// let url = build(&config, "/path", query).unwrap();
 /// assert_eq!(url, "/path?baz=qux&foo=bar&signature=25a3d00acee5bf7c1e71f0ce8addab046710221dbc12d0d1ce0a931a6c5f5add");
 /// ```
 ///
 /// # Errors
 ///
 /// Returns `Err` if there is an error while signing the URL.
-pub fn build(path: &str, query: HashMap<&str, &str>) -> Result<String> {
+pub fn build(config: &SignedUrlConfig, path: &str, query: HashMap<&str, &str>) -> Result<String> {
+    sign(config, path, query.into_iter().collect(), None, None)
+}
+
+/// Builder for signed URLs that expire after a given duration.
+///
+/// The expiry is stored as a Unix timestamp in the `expires` query pair, and is included in the
+/// signed portion of the URL so it can't be tampered with independently of the signature.
+///
+/// # Example
+///
+/// ```rust
+/// use axum_signed_urls::{build_with_expiry, SignedUrlConfig};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let config = SignedUrlConfig::new("hunter2");
+/// let url = build_with_expiry(&config, "/path", HashMap::new(), Duration::from_secs(60)).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if there is an error while signing the URL.
+pub fn build_with_expiry(
+    config: &SignedUrlConfig,
+    path: &str,
+    query: HashMap<&str, &str>,
+    expires_in: Duration,
+) -> Result<String> {
+    sign_with_expiry(config, path, query, None, expires_in)
+}
+
+/// Builder for signed URLs that bind the request's HTTP method, preventing a URL signed for one
+/// method (e.g. a safe `GET`) from being replayed against another (e.g. `POST`/`DELETE`) handler
+/// that also uses the [`SignedUrl`] extractor.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::http::Method;
+/// use axum_signed_urls::{build_for_method, SignedUrlConfig};
+/// use std::collections::HashMap;
+///
+/// let config = SignedUrlConfig::new("hunter2");
+/// let url = build_for_method(&config, Method::DELETE, "/path", HashMap::new()).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if there is an error while signing the URL.
+pub fn build_for_method(
+    config: &SignedUrlConfig,
+    method: Method,
+    path: &str,
+    query: HashMap<&str, &str>,
+) -> Result<String> {
+    sign(
+        config,
+        path,
+        query.into_iter().collect(),
+        Some(method.as_str()),
+        None,
+    )
+}
+
+/// Builder combining [`build_for_method`] and [`build_with_expiry`]: binds both the HTTP method
+/// and an expiry timestamp into the signature.
+///
+/// Use this instead of [`build_with_expiry`] once [`SignedUrlConfig::require_method`] is enabled,
+/// since an expiring URL built without a method would otherwise never validate.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::http::Method;
+/// use axum_signed_urls::{build_for_method_with_expiry, SignedUrlConfig};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let config = SignedUrlConfig::new("hunter2");
+/// let url = build_for_method_with_expiry(
+///     &config,
+///     Method::DELETE,
+///     "/path",
+///     HashMap::new(),
+///     Duration::from_secs(60),
+/// )
+/// .unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if there is an error while signing the URL.
+pub fn build_for_method_with_expiry(
+    config: &SignedUrlConfig,
+    method: Method,
+    path: &str,
+    query: HashMap<&str, &str>,
+    expires_in: Duration,
+) -> Result<String> {
+    sign_with_expiry(config, path, query, Some(method.as_str()), expires_in)
+}
+
+/// Builder for signed URLs that also pin a caller-chosen set of request headers, analogous to
+/// SigV4's signed-headers list.
+///
+/// The signed header names are recorded (lowercased, comma-separated) in the `signed-headers`
+/// query pair, so the extractor knows which request headers to canonicalize and verify.
+///
+/// # Example
+///
+/// ```rust
+/// use axum_signed_urls::{build_with_headers, SignedUrlConfig};
+/// use std::collections::HashMap;
+///
+/// let config = SignedUrlConfig::new("hunter2");
+/// let url = build_with_headers(&config, "/path", HashMap::new(), &[("content-type", "application/json")]).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if there is an error while signing the URL.
+pub fn build_with_headers(
+    config: &SignedUrlConfig,
+    path: &str,
+    query: HashMap<&str, &str>,
+    signed_headers: &[(&str, &str)],
+) -> Result<String> {
+    sign_with_headers(config, path, query, None, signed_headers)
+}
+
+/// Builder combining [`build_for_method`] and [`build_with_headers`]: binds both the HTTP method
+/// and a caller-chosen set of request headers into the signature.
+///
+/// Use this instead of [`build_with_headers`] once [`SignedUrlConfig::require_method`] is
+/// enabled, since a header-signed URL built without a method would otherwise never validate.
+///
+/// # Example
+///
+/// ```rust
+/// use axum::http::Method;
+/// use axum_signed_urls::{build_for_method_with_headers, SignedUrlConfig};
+/// use std::collections::HashMap;
+///
+/// let config = SignedUrlConfig::new("hunter2");
+/// let url = build_for_method_with_headers(
+///     &config,
+///     Method::DELETE,
+///     "/path",
+///     HashMap::new(),
+///     &[("content-type", "application/json")],
+/// )
+/// .unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns `Err` if there is an error while signing the URL.
+pub fn build_for_method_with_headers(
+    config: &SignedUrlConfig,
+    method: Method,
+    path: &str,
+    query: HashMap<&str, &str>,
+    signed_headers: &[(&str, &str)],
+) -> Result<String> {
+    sign_with_headers(config, path, query, Some(method.as_str()), signed_headers)
+}
+
+fn sign_with_expiry(
+    config: &SignedUrlConfig,
+    path: &str,
+    query: HashMap<&str, &str>,
+    method: Option<&str>,
+    expires_in: Duration,
+) -> Result<String> {
+    let expires_at = (SystemTime::now() + expires_in)
+        .duration_since(UNIX_EPOCH)
+        .context("system time is before the Unix epoch")?
+        .as_secs()
+        .to_string();
+
+    let mut query: Vec<(&str, &str)> = query.into_iter().collect();
+    query.push(("expires", &expires_at));
+
+    sign(config, path, query, method, None)
+}
+
+fn sign_with_headers(
+    config: &SignedUrlConfig,
+    path: &str,
+    query: HashMap<&str, &str>,
+    method: Option<&str>,
+    signed_headers: &[(&str, &str)],
+) -> Result<String> {
+    let mut signed_headers = signed_headers.to_vec();
+    signed_headers.sort_by(|(k1, _), (k2, _)| k1.to_lowercase().cmp(&k2.to_lowercase()));
+
+    let header_names = signed_headers
+        .iter()
+        .map(|(name, _)| name.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(",");
+
     let mut query: Vec<(&str, &str)> = query.into_iter().collect();
+    query.push(("signed-headers", &header_names));
+
+    sign(config, path, query, method, Some(&signed_headers))
+}
+
+fn sign(
+    config: &SignedUrlConfig,
+    path: &str,
+    mut query: Vec<(&str, &str)>,
+    method: Option<&str>,
+    signed_headers: Option<&[(&str, &str)]>,
+) -> Result<String> {
     query.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
 
     let mut query = QString::new(query);
+    let unsigned_url = format!("{path}{}", stringify_query(&query));
 
-    let signature = hmac_sha256(&format!("{path}{}", stringify_query(&query)))?;
+    let mut signed_data = match method {
+        Some(method) => format!("{method}{unsigned_url}"),
+        None => unsigned_url,
+    };
+
+    if let Some(signed_headers) = signed_headers {
+        signed_data.push_str(&canonical_headers(signed_headers));
+    }
+
+    let signature = hmac_sha256(&config.current_key, &signed_data)?;
     query.add_pair(("signature", &signature));
 
     Ok(format!("{path}{}", stringify_query(&query)))
 }
 
+/// Canonicalizes a signed-headers block: `name` lowercased, one `name:value` pair per line, in
+/// the order given (callers are expected to pass headers pre-sorted by name).
+fn canonical_headers(headers: &[(&str, &str)]) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| format!("{}:{value}\n", name.to_lowercase()))
+        .collect()
+}
+
 fn stringify_query(query: &QString) -> String {
     if query.is_empty() {
         String::new()
@@ -114,17 +495,27 @@ fn stringify_query(query: &QString) -> String {
 
 type HmacSha256 = Hmac<Sha256>;
 
-fn hmac_sha256<T: AsRef<[u8]>>(data: &T) -> Result<String> {
-    let app_key = env::var("AXUM_SECRET").context("AXUM_SECRET not found")?;
-
+fn hmac_sha256<T: AsRef<[u8]>>(key: &str, data: &T) -> Result<String> {
     Ok(hex::encode(
-        HmacSha256::new_from_slice(app_key.as_bytes())?
+        HmacSha256::new_from_slice(key.as_bytes())?
             .chain_update(data)
             .finalize()
             .into_bytes(),
     ))
 }
 
+/// Verifies `signature` against `data` in constant time, to avoid leaking the correct signature
+/// one byte at a time through response-latency side channels.
+fn verify_signature<T: AsRef<[u8]>>(key: &str, data: &T, signature: &str) -> Result<()> {
+    let signature = hex::decode(signature)?;
+
+    HmacSha256::new_from_slice(key.as_bytes())?
+        .chain_update(data)
+        .verify_slice(&signature)?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,12 +525,27 @@ mod tests {
     use http::Request;
     use map_macro::map;
 
+    #[derive(Clone)]
+    struct AppState {
+        signed_urls: SignedUrlConfig,
+    }
+
+    impl FromRef<AppState> for SignedUrlConfig {
+        fn from_ref(state: &AppState) -> Self {
+            state.signed_urls.clone()
+        }
+    }
+
+    fn test_state() -> AppState {
+        AppState {
+            signed_urls: SignedUrlConfig::new("hunter2"),
+        }
+    }
+
     #[test]
     fn hmac_sha256_matches_snapshot() {
-        env::set_var("AXUM_SECRET", "hunter2");
-
         assert_eq!(
-            hmac_sha256(&"test").unwrap(),
+            hmac_sha256("hunter2", &"test").unwrap(),
             "4e99265a03bc2001089f7196919be9bbf5b81a557fbb7ea9907a18a461437a04"
         );
     }
@@ -148,71 +554,338 @@ mod tests {
     #[test]
     fn fails_when_axum_secret_not_set() {
         env::remove_var("AXUM_SECRET");
-        let err = hmac_sha256(&"test").unwrap_err();
+        let err = SignedUrlConfig::from_env().unwrap_err();
 
         assert_eq!(err.to_string(), "AXUM_SECRET not found");
     }
 
     #[tokio::test]
     async fn validates_signed_url() {
-        env::set_var("AXUM_SECRET", "hunter2");
+        let state = test_state();
 
         let req = Request::builder()
             .uri(format!(
                 "https://example.com{}",
-                build("/hi", map! {"email" => "miguel@example.com"}).unwrap()
+                build(
+                    &state.signed_urls,
+                    "/hi",
+                    map! {"email" => "miguel@example.com"}
+                )
+                .unwrap()
             ))
             .body(())
             .unwrap();
 
-        SignedUrl::from_request(req, &()).await.unwrap();
+        SignedUrl::from_request(req, &state).await.unwrap();
     }
 
     #[tokio::test]
     async fn throws_unauthorized_error_on_invalid_signature() {
-        env::set_var("AXUM_SECRET", "hunter2");
+        let state = test_state();
 
         let req = Request::builder()
             .uri(format!(
                 "https://example.com{}",
-                build("/login", map! {"email" => "miguel@example.com"})
-                    .unwrap()
-                    .replace("miguel@", "admin@")
+                build(
+                    &state.signed_urls,
+                    "/login",
+                    map! {"email" => "miguel@example.com"}
+                )
+                .unwrap()
+                .replace("miguel@", "admin@")
             ))
             .body(())
             .unwrap();
 
-        let err = SignedUrl::from_request(req, &()).await.unwrap_err();
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
 
         assert_eq!(err, (StatusCode::UNAUTHORIZED, "Missing signature"));
     }
 
     #[tokio::test]
     async fn throws_unauthorized_error_on_missing_signature() {
-        env::set_var("AXUM_SECRET", "hunter2");
+        let state = test_state();
 
         let req = Request::builder()
             .uri("https://example.com/hello?email=admin@example.com")
             .body(())
             .unwrap();
 
-        let err = SignedUrl::from_request(req, &()).await.unwrap_err();
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
 
         assert_eq!(err, (StatusCode::UNAUTHORIZED, "Missing signature"));
     }
 
     #[tokio::test]
     async fn works_without_extra_query_params() {
-        env::set_var("AXUM_SECRET", "hunter2");
+        let state = test_state();
+
+        let req = Request::builder()
+            .uri(format!(
+                "https://example.com{}",
+                build(&state.signed_urls, "/test", map! {}).unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn validates_unexpired_url() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .uri(format!(
+                "https://example.com{}",
+                build_with_expiry(&state.signed_urls, "/hi", map! {}, Duration::from_secs(60))
+                    .unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn throws_unauthorized_error_on_expired_url() {
+        let state = test_state();
+
+        let url =
+            build_with_expiry(&state.signed_urls, "/hi", map! {}, Duration::from_secs(0)).unwrap();
+        std::thread::sleep(Duration::from_millis(1100));
+
+        let req = Request::builder()
+            .uri(format!("https://example.com{url}"))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "URL expired"));
+    }
+
+    #[tokio::test]
+    async fn validates_signature_under_a_retired_key() {
+        let old_config = SignedUrlConfig::new("old-secret");
+        let url = build(&old_config, "/hi", map! {}).unwrap();
+
+        let state = AppState {
+            signed_urls: SignedUrlConfig::new("hunter2").with_previous_key("old-secret"),
+        };
+
+        let req = Request::builder()
+            .uri(format!("https://example.com{url}"))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_signature_under_a_fully_retired_key() {
+        let old_config = SignedUrlConfig::new("old-secret");
+        let url = build(&old_config, "/hi", map! {}).unwrap();
+
+        let state = test_state();
+
+        let req = Request::builder()
+            .uri(format!("https://example.com{url}"))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn validates_method_bound_url_under_the_matching_method() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .method(Method::DELETE)
+            .uri(format!(
+                "https://example.com{}",
+                build_for_method(&state.signed_urls, Method::DELETE, "/hi", map! {}).unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_method_bound_url_replayed_under_a_different_method() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri(format!(
+                "https://example.com{}",
+                build_for_method(&state.signed_urls, Method::DELETE, "/hi", map! {}).unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn accepts_method_agnostic_signatures_during_migration() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .method(Method::DELETE)
+            .uri(format!(
+                "https://example.com{}",
+                build(&state.signed_urls, "/hi", map! {}).unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_method_agnostic_signatures_once_method_is_required() {
+        let state = AppState {
+            signed_urls: SignedUrlConfig::new("hunter2").require_method(),
+        };
+
+        let req = Request::builder()
+            .method(Method::DELETE)
+            .uri(format!(
+                "https://example.com{}",
+                build(&state.signed_urls, "/hi", map! {}).unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn validates_method_and_expiry_bound_url_under_a_required_method() {
+        let state = AppState {
+            signed_urls: SignedUrlConfig::new("hunter2").require_method(),
+        };
+
+        let req = Request::builder()
+            .method(Method::DELETE)
+            .uri(format!(
+                "https://example.com{}",
+                build_for_method_with_expiry(
+                    &state.signed_urls,
+                    Method::DELETE,
+                    "/hi",
+                    map! {},
+                    Duration::from_secs(60)
+                )
+                .unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn validates_url_with_matching_signed_headers() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .header("content-type", "application/json")
+            .uri(format!(
+                "https://example.com{}",
+                build_with_headers(
+                    &state.signed_urls,
+                    "/hi",
+                    map! {},
+                    &[("content-type", "application/json")]
+                )
+                .unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        SignedUrl::from_request(req, &state).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_url_with_altered_signed_header() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .header("content-type", "text/plain")
+            .uri(format!(
+                "https://example.com{}",
+                build_with_headers(
+                    &state.signed_urls,
+                    "/hi",
+                    map! {},
+                    &[("content-type", "application/json")]
+                )
+                .unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn rejects_url_missing_a_signed_header() {
+        let state = test_state();
+
+        let req = Request::builder()
+            .uri(format!(
+                "https://example.com{}",
+                build_with_headers(
+                    &state.signed_urls,
+                    "/hi",
+                    map! {},
+                    &[("content-type", "application/json")]
+                )
+                .unwrap()
+            ))
+            .body(())
+            .unwrap();
+
+        let err = SignedUrl::from_request(req, &state).await.unwrap_err();
+
+        assert_eq!(err, (StatusCode::UNAUTHORIZED, "Invalid signature"));
+    }
+
+    #[tokio::test]
+    async fn validates_method_and_header_bound_url_under_a_required_method() {
+        let state = AppState {
+            signed_urls: SignedUrlConfig::new("hunter2").require_method(),
+        };
 
         let req = Request::builder()
+            .method(Method::DELETE)
+            .header("content-type", "application/json")
             .uri(format!(
                 "https://example.com{}",
-                build("/test", map! {}).unwrap()
+                build_for_method_with_headers(
+                    &state.signed_urls,
+                    Method::DELETE,
+                    "/hi",
+                    map! {},
+                    &[("content-type", "application/json")]
+                )
+                .unwrap()
             ))
             .body(())
             .unwrap();
 
-        SignedUrl::from_request(req, &()).await.unwrap();
+        SignedUrl::from_request(req, &state).await.unwrap();
     }
 }